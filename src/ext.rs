@@ -0,0 +1,147 @@
+//! Extension trait exposing the bisection operations as slice methods.
+//!
+//! This mirrors the ergonomics of crates such as `superslice`, letting
+//! callers write `a.lower_bound(&x)` instead of `bisect_left(&a, &x)`.
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::{
+    bisect_left, bisect_left_by, bisect_left_by_key, bisect_right, bisect_right_by,
+    bisect_right_by_key, equal_range, equal_range_by, equal_range_by_key,
+};
+
+/// Bisection operations available directly on sorted slices.
+///
+/// Every method here delegates to the free function of the same shape
+/// (e.g. [`Bisect::lower_bound`] calls [`bisect_left`]); see those functions
+/// for the exact semantics of the index returned.
+pub trait Bisect<T> {
+    /// Equivalent to [`bisect_left`].
+    fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord;
+
+    /// Equivalent to [`bisect_right`].
+    fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord;
+
+    /// Equivalent to [`crate::bisect_left_by`].
+    fn lower_bound_by<F>(&self, f: F) -> usize
+    where
+        T: Ord,
+        F: FnMut(&T) -> Ordering;
+
+    /// Equivalent to [`crate::bisect_right_by`].
+    fn upper_bound_by<F>(&self, f: F) -> usize
+    where
+        T: Ord,
+        F: FnMut(&T) -> Ordering;
+
+    /// Equivalent to [`bisect_left_by_key`].
+    fn lower_bound_by_key<B, F>(&self, b: &B, f: F) -> usize
+    where
+        T: Ord,
+        B: Ord,
+        F: FnMut(&T) -> B;
+
+    /// Equivalent to [`bisect_right_by_key`].
+    fn upper_bound_by_key<B, F>(&self, b: &B, f: F) -> usize
+    where
+        T: Ord,
+        B: Ord,
+        F: FnMut(&T) -> B;
+
+    /// Returns the half-open range of indices of all elements equal to `x`,
+    /// i.e. `self.lower_bound(x)..self.upper_bound(x)`.
+    fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord;
+
+    /// `equal_range` using a custom comparator, see [`Bisect::equal_range`].
+    fn equal_range_by<F>(&self, f: F) -> Range<usize>
+    where
+        T: Ord,
+        F: FnMut(&T) -> Ordering;
+
+    /// `equal_range` using a key extraction function, see [`Bisect::equal_range`].
+    fn equal_range_by_key<B, F>(&self, b: &B, f: F) -> Range<usize>
+    where
+        T: Ord,
+        B: Ord,
+        F: FnMut(&T) -> B;
+}
+
+impl<T> Bisect<T> for [T] {
+    fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        bisect_left(self, x)
+    }
+
+    fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        bisect_right(self, x)
+    }
+
+    fn lower_bound_by<F>(&self, f: F) -> usize
+    where
+        T: Ord,
+        F: FnMut(&T) -> Ordering,
+    {
+        bisect_left_by(self, f)
+    }
+
+    fn upper_bound_by<F>(&self, f: F) -> usize
+    where
+        T: Ord,
+        F: FnMut(&T) -> Ordering,
+    {
+        bisect_right_by(self, f)
+    }
+
+    fn lower_bound_by_key<B, F>(&self, b: &B, f: F) -> usize
+    where
+        T: Ord,
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        bisect_left_by_key(self, b, f)
+    }
+
+    fn upper_bound_by_key<B, F>(&self, b: &B, f: F) -> usize
+    where
+        T: Ord,
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        bisect_right_by_key(self, b, f)
+    }
+
+    fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord,
+    {
+        equal_range(self, x)
+    }
+
+    fn equal_range_by<F>(&self, f: F) -> Range<usize>
+    where
+        T: Ord,
+        F: FnMut(&T) -> Ordering,
+    {
+        equal_range_by(self, f)
+    }
+
+    fn equal_range_by_key<B, F>(&self, b: &B, f: F) -> Range<usize>
+    where
+        T: Ord,
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        equal_range_by_key(self, b, f)
+    }
+}
@@ -0,0 +1,190 @@
+//! Insert into a sorted `Vec` while preserving order.
+//!
+//! Ports Python's `bisect.insort_left`/`bisect.insort_right`: the insertion
+//! point is located via the corresponding bisect, then the element is
+//! inserted with [`Vec::insert`].
+use std::cmp::Ordering;
+use std::ops::RangeBounds;
+use std::slice::SliceIndex;
+
+use crate::{bisect_left_in, bisect_left_in_by, bisect_right_in, bisect_right_in_by};
+
+/// Insert `x` into `v`, keeping it sorted, before any existing entries equal
+/// to `x`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::insort_left;
+/// let mut v = vec![1, 2, 2, 3];
+///
+/// insort_left(&mut v, 2);
+/// assert_eq!(v, [1, 2, 2, 2, 3]);
+/// ```
+pub fn insort_left<T>(v: &mut Vec<T>, x: T)
+where
+    T: Ord,
+{
+    insort_left_in(v, x, ..)
+}
+
+/// Like [`insort_left`], but only searches the insertion point within
+/// `range` of `v`.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `v`, same as slice indexing.
+pub fn insort_left_in<T, R>(v: &mut Vec<T>, x: T, range: R)
+where
+    T: Ord,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let idx = bisect_left_in(v, &x, range);
+    v.insert(idx, x);
+}
+
+/// Like [`insort_left`], but locates the insertion point with a custom
+/// comparator, see [`crate::bisect_left_by`].
+pub fn insort_left_by<T, F>(v: &mut Vec<T>, x: T, f: F)
+where
+    T: Ord,
+    F: FnMut(&T) -> Ordering,
+{
+    insort_left_by_in(v, x, .., f)
+}
+
+/// Like [`insort_left_by`], but only searches the insertion point within
+/// `range` of `v`.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `v`, same as slice indexing.
+pub fn insort_left_by_in<T, F, R>(v: &mut Vec<T>, x: T, range: R, f: F)
+where
+    T: Ord,
+    F: FnMut(&T) -> Ordering,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let idx = bisect_left_in_by(v, range, f);
+    v.insert(idx, x);
+}
+
+/// Like [`insort_left`], but locates the insertion point using a key
+/// extraction function, see [`crate::bisect_left_by_key`].
+pub fn insort_left_by_key<T, B, F>(v: &mut Vec<T>, x: T, key: F)
+where
+    T: Ord,
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    insort_left_by_key_in(v, x, .., key)
+}
+
+/// Like [`insort_left_by_key`], but only searches the insertion point within
+/// `range` of `v`.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `v`, same as slice indexing.
+pub fn insort_left_by_key_in<T, B, F, R>(v: &mut Vec<T>, x: T, range: R, mut key: F)
+where
+    T: Ord,
+    B: Ord,
+    F: FnMut(&T) -> B,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let b = key(&x);
+    insort_left_by_in(v, x, range, move |k| key(k).cmp(&b))
+}
+
+/// Insert `x` into `v`, keeping it sorted, after any existing entries equal
+/// to `x`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::insort_right;
+/// let mut v = vec![1, 2, 2, 3];
+///
+/// insort_right(&mut v, 2);
+/// assert_eq!(v, [1, 2, 2, 2, 3]);
+/// ```
+pub fn insort_right<T>(v: &mut Vec<T>, x: T)
+where
+    T: Ord,
+{
+    insort_right_in(v, x, ..)
+}
+
+/// Like [`insort_right`], but only searches the insertion point within
+/// `range` of `v`.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `v`, same as slice indexing.
+pub fn insort_right_in<T, R>(v: &mut Vec<T>, x: T, range: R)
+where
+    T: Ord,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let idx = bisect_right_in(v, &x, range);
+    v.insert(idx, x);
+}
+
+/// Like [`insort_right`], but locates the insertion point with a custom
+/// comparator, see [`crate::bisect_right_by`].
+pub fn insort_right_by<T, F>(v: &mut Vec<T>, x: T, f: F)
+where
+    T: Ord,
+    F: FnMut(&T) -> Ordering,
+{
+    insort_right_by_in(v, x, .., f)
+}
+
+/// Like [`insort_right_by`], but only searches the insertion point within
+/// `range` of `v`.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `v`, same as slice indexing.
+pub fn insort_right_by_in<T, F, R>(v: &mut Vec<T>, x: T, range: R, f: F)
+where
+    T: Ord,
+    F: FnMut(&T) -> Ordering,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let idx = bisect_right_in_by(v, range, f);
+    v.insert(idx, x);
+}
+
+/// Like [`insort_right`], but locates the insertion point using a key
+/// extraction function, see [`crate::bisect_right_by_key`].
+pub fn insort_right_by_key<T, B, F>(v: &mut Vec<T>, x: T, key: F)
+where
+    T: Ord,
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    insort_right_by_key_in(v, x, .., key)
+}
+
+/// Like [`insort_right_by_key`], but only searches the insertion point
+/// within `range` of `v`.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `v`, same as slice indexing.
+pub fn insort_right_by_key_in<T, B, F, R>(v: &mut Vec<T>, x: T, range: R, mut key: F)
+where
+    T: Ord,
+    B: Ord,
+    F: FnMut(&T) -> B,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let b = key(&x);
+    insort_right_by_in(v, x, range, move |k| key(k).cmp(&b))
+}
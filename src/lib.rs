@@ -9,6 +9,20 @@
 //! relative to the element found. 
 #![warn(rust_2018_idioms, nonstandard_style, missing_docs)]
 use std::cmp::Ordering::{self, Equal, Greater, Less};
+use std::ops::{Range, RangeBounds};
+use std::slice::SliceIndex;
+
+mod ext;
+mod insort;
+mod range;
+
+pub use ext::Bisect;
+pub use insort::{
+    insort_left, insort_left_by, insort_left_by_in, insort_left_by_key, insort_left_by_key_in,
+    insort_left_in, insort_right, insort_right_by, insort_right_by_in, insort_right_by_key,
+    insort_right_by_key_in, insort_right_in,
+};
+pub use range::{bisect_range, bisect_range_by};
 
 /// Search for the element and return the rightmost index that can be used to insert
 /// it into the sorted slice while maintaining sort order.
@@ -32,8 +46,10 @@ use std::cmp::Ordering::{self, Equal, Greater, Less};
 /// ```
 /// # Panics
 ///
-/// In `slice.len() == usize::MAX` and a search is made for an element that's equal or larger than
-/// the last element in the slice, overflow occurs due to trying to add one to the max value of `usize`.
+/// Does not panic: the midpoint of the search is computed as `low + (high - low) / 2`, which
+/// cannot overflow even for `slice.len() == usize::MAX`. See [`try_bisect_right`] for a variant
+/// that surfaces the (practically unreachable) overflow case as `None` instead of relying on
+/// that invariant.
 pub fn bisect_right<T>(a: &[T], x: &T) -> usize
 where
     T: Ord,
@@ -41,6 +57,31 @@ where
     bisect_right_by(a, |k| k.cmp(x))
 }
 
+/// Fallible version of [`bisect_right`] that returns `None` instead of panicking if computing
+/// the insertion point would ever overflow `usize`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::try_bisect_right;
+/// let u = [0, 1, 2, 2, 3, 4];
+///
+/// assert_eq!(try_bisect_right(&u, &4), Some(6));
+///
+/// // Even a slice filled with usize::MAX equal, zero-sized elements is handled without
+/// // overflowing or panicking.
+/// let b = [(); usize::MAX];
+/// assert_eq!(try_bisect_right(&b, &()), Some(usize::MAX));
+/// ```
+pub fn try_bisect_right<T>(a: &[T], x: &T) -> Option<usize>
+where
+    T: Ord,
+{
+    try_bisect_right_by(a, |k| k.cmp(x))
+}
+
 /// Search for an element using a key extraction function and return the rightmost index
 /// that can be used to insert it into the sorted slice while maintaining sort order.
 ///
@@ -70,8 +111,7 @@ where
 ///
 /// # Panics
 ///
-/// In `slice.len() == usize::MAX` and a search is made for an element that's equal or larger than
-/// the last element in the slice, overflow occurs due to trying to add one to the max value of `usize`.
+/// Does not panic; see [`bisect_right`] for why the midpoint computation cannot overflow.
 pub fn bisect_right_by_key<T, B, F>(a: &[T], b: &B, mut f: F) -> usize
 where
     T: Ord,
@@ -114,31 +154,56 @@ where
 ///
 /// # Panics
 ///
-/// In `slice.len() == usize::MAX` and a search is made for an element that's equal or larger than
-/// the last element in the slice, overflow occurs due to trying to add one to the max value of `usize`.
-pub fn bisect_right_by<T, F>(a: &[T], mut f: F) -> usize
+/// Does not panic; see [`bisect_right`] for why the midpoint computation cannot overflow.
+pub fn bisect_right_by<T, F>(a: &[T], f: F) -> usize
+where
+    T: Ord,
+    F: FnMut(&T) -> Ordering,
+{
+    try_bisect_right_by(a, f).expect("insertion point overflows usize")
+}
+
+/// Fallible version of [`bisect_right_by`] that returns `None` instead of panicking when the
+/// insertion point would overflow `usize`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::try_bisect_right_by;
+///
+/// let s = [0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+/// let seek = 13;
+/// assert_eq!(try_bisect_right_by(&s, |probe| probe.cmp(&seek)), Some(10));
+///
+/// let b = [(); usize::MAX];
+/// assert_eq!(try_bisect_right_by(&b, |_| std::cmp::Ordering::Less), Some(usize::MAX));
+/// ```
+pub fn try_bisect_right_by<T, F>(a: &[T], mut f: F) -> Option<usize>
 where
     T: Ord,
     F: FnMut(&T) -> Ordering,
 {
     let mut high = a.len();
     if high == 0 {
-        return 0;
+        return Some(0);
     }
     let mut low = 0;
     while low < high {
-        let mid = (low + high) / 2;
+        // Computed as `low + (high - low) / 2` rather than `(low + high) / 2` so that it
+        // cannot overflow even when `low + high` would.
+        let mid = low + (high - low) / 2;
         // SAFETY: mid always ranges between [0, high).
         //         Lowest possible value of mid occurs when low == 0 and high == 1, mid == 0.
         //         Highest possible value of mid occurs when high = a.len() and low == high-1,
-        //         in that case mid will be (2 * high - 1) / 2. This equals (high - 0.5) which,
-        //         due to truncation will result in a value of (high - 1).
+        //         in that case mid equals high - 1.
         match f(unsafe { a.get_unchecked(mid) }) {
-            Less | Equal => low = mid + 1, // a[mid] <= x
-            Greater => high = mid,         // a[mid] > x
+            Less | Equal => low = mid.checked_add(1)?, // a[mid] <= x
+            Greater => high = mid,                     // a[mid] > x
         }
     }
-    low
+    Some(low)
 }
 
 /// Search for the element and return the leftmost index that can be used to insert
@@ -244,12 +309,13 @@ where
     }
     let mut low = 0;
     while low < high {
-        let mid = (low + high) / 2;
+        // Computed as `low + (high - low) / 2` rather than `(low + high) / 2` so that it
+        // cannot overflow even when `low + high` would.
+        let mid = low + (high - low) / 2;
         // SAFETY: mid always ranges between [0, high).
         //         Lowest possible value of mid occurs when low == 0 and high == 1, mid == 0.
         //         Highest possible value of mid occurs when high = a.len() and low == high-1,
-        //         in that case mid will be (2 * high - 1) / 2. This equals (high - 0.5) which,
-        //         due to truncation will result in a value of (high - 1).
+        //         in that case mid equals high - 1.
         match f(unsafe { a.get_unchecked(mid) }) {
             Less => low = mid + 1,         // a[mid] < x
             Greater | Equal => high = mid, // a[mid] >= x
@@ -258,33 +324,182 @@ where
     low
 }
 
-// I'm still unsure if this is really needed. Searching Github for usages of
-// bisecting with bounds specified in Python, I currently only found hits for code
-// implementing a Trie (and my quick glance of it gives me the impression it might be
-// for optimization purposes).
-//
-// If needed, all bounded forms follow the pattern as seen in `bisect_slice_right`. 
-// We grab the start of the bound, and return start + bisect_*(slice[bound], x). 
-#[allow(dead_code)]
-fn bisect_slice_right<T, B>(a: &[T], x: &T, bound: B) -> usize 
-where 
+/// Search for the element and return the range of indices `lo..hi` such that
+/// `&a[lo..hi]` contains exactly the elements equal to `x`, i.e.
+/// `bisect_left(a, x)..bisect_right(a, x)`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::equal_range;
+/// let u = [0, 1, 2, 2, 3, 4];
+///
+/// assert_eq!(equal_range(&u, &2), 2..4);
+/// assert_eq!(equal_range(&u, &5), 6..6);
+/// ```
+pub fn equal_range<T>(a: &[T], x: &T) -> Range<usize>
+where
+    T: Ord,
+{
+    equal_range_by(a, |k| k.cmp(x))
+}
+
+/// Search for an element using a key extraction function and return the range
+/// of indices `lo..hi` such that `&a[lo..hi]` contains exactly the elements
+/// whose key is equal to `b`, see [`equal_range`].
+///
+/// Assumes that the slice is sorted by the key, for instance with
+/// `sort_by_key` using the same key extraction function.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::equal_range_by_key;
+///
+/// let u = vec![(1, 3), (5, 5), (5, 6), (10, 2), (11, 2)];
+/// assert_eq!(equal_range_by_key(&u, &5, |&(a, _)| a), 1..3);
+/// ```
+pub fn equal_range_by_key<T, B, F>(a: &[T], b: &B, mut f: F) -> Range<usize>
+where
+    T: Ord,
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    equal_range_by(a, |k| f(k).cmp(b))
+}
+
+/// Search for an element using a comparator function and return the range of
+/// indices `lo..hi` such that `&a[lo..hi]` contains exactly the elements that
+/// compare `Equal` to the target, see [`equal_range`].
+///
+/// Rather than running `bisect_left_by` and `bisect_right_by` as two
+/// independent full-height searches, this shares a single descent: the loop
+/// narrows `[low, high)` as usual and stops as soon as an `Equal` element is
+/// found. At that point the left boundary is known to lie in `[low, mid]` and
+/// the right boundary in `[mid+1, high]`, so the remaining work is a
+/// `bisect_left_by`/`bisect_right_by` over those much smaller sub-slices.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::equal_range_by;
+///
+/// let s = [0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+///
+/// let seek = 1;
+/// assert_eq!(equal_range_by(&s, |probe| probe.cmp(&seek)), 1..5);
+/// ```
+pub fn equal_range_by<T, F>(a: &[T], mut f: F) -> Range<usize>
+where
     T: Ord,
-    B: std::ops::RangeBounds<usize> + std::slice::SliceIndex<[T], Output = [T]>
+    F: FnMut(&T) -> Ordering,
 {
-    let start = bounds_start(&bound);
-    // Note: Invalid bound panics here.
-    start + bisect_right_by(&a[bound], |k| k.cmp(x))
+    let mut low = 0;
+    let mut high = a.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        // SAFETY: mid always ranges between [low, high) while low < high.
+        match f(unsafe { a.get_unchecked(mid) }) {
+            Less => low = mid + 1,
+            Greater => high = mid,
+            Equal => {
+                let left = low + bisect_left_by(&a[low..mid], &mut f);
+                let right = (mid + 1) + bisect_right_by(&a[mid + 1..high], &mut f);
+                return left..right;
+            }
+        }
+    }
+    low..low
+}
+
+/// Search for the element within `range` of the sorted slice and return the
+/// leftmost index, relative to the start of `a`, that can be used to insert
+/// it while maintaining sort order, see [`bisect_left`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::bisect_left_in;
+/// let u = [0, 1, 2, 2, 2, 3, 4];
+///
+/// assert_eq!(bisect_left_in(&u, &2, 3..), 3);
+/// assert_eq!(bisect_left_in(&u, &0, 1..), 1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `a`, same as slice indexing.
+pub fn bisect_left_in<T, R>(a: &[T], x: &T, range: R) -> usize
+where
+    T: Ord,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    bisect_left_in_by(a, range, |k| k.cmp(x))
+}
+
+/// Search for the element within `range` of the sorted slice and return the
+/// rightmost index, relative to the start of `a`, that can be used to insert
+/// it while maintaining sort order, see [`bisect_right`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::bisect_right_in;
+/// let u = [0, 1, 2, 2, 2, 3, 4];
+///
+/// assert_eq!(bisect_right_in(&u, &2, ..4), 4);
+/// assert_eq!(bisect_right_in(&u, &2, ..2), 2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds for `a`, same as slice indexing.
+pub fn bisect_right_in<T, R>(a: &[T], x: &T, range: R) -> usize
+where
+    T: Ord,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    bisect_right_in_by(a, range, |k| k.cmp(x))
+}
+
+pub(crate) fn bisect_left_in_by<T, F, R>(a: &[T], range: R, f: F) -> usize
+where
+    T: Ord,
+    F: FnMut(&T) -> Ordering,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let start = bounds_start(&range);
+    start + bisect_left_by(&a[range], f)
+}
+
+pub(crate) fn bisect_right_in_by<T, F, R>(a: &[T], range: R, f: F) -> usize
+where
+    T: Ord,
+    F: FnMut(&T) -> Ordering,
+    R: RangeBounds<usize> + SliceIndex<[T], Output = [T]>,
+{
+    let start = bounds_start(&range);
+    start + bisect_right_by(&a[range], f)
 }
 
-#[allow(dead_code)]
 #[inline]
 fn bounds_start<B>(bounds: &B) -> usize
-where 
-    B: std::ops::RangeBounds<usize> 
+where
+    B: RangeBounds<usize>,
 {
     match bounds.start_bound() {
         std::ops::Bound::Unbounded => 0,
-        std::ops::Bound::Excluded(&x) => x-1,
+        std::ops::Bound::Excluded(&x) => x + 1,
         std::ops::Bound::Included(&x) => x,
     }
 }
\ No newline at end of file
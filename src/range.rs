@@ -0,0 +1,67 @@
+//! Bisect over a monotonic predicate across an abstract range, without
+//! requiring a materialized slice.
+
+/// Returns the first index in `[lo, hi)` where `pred` transitions from
+/// `false` to `true`, assuming `pred` is monotonic over the range (i.e. once
+/// it returns `true` it returns `true` for every larger index). If `pred`
+/// never returns `true`, `hi` is returned.
+///
+/// This generalizes bisection beyond sorted-slice lookup to "binary search
+/// on the answer": `pred` can probe any monotonic condition over an
+/// abstract index space, such as stepping over a version/release index to
+/// find where a bug was introduced.
+///
+/// `pred` is called at most `O(log(hi - lo))` times, and never on an index
+/// outside `[lo, hi)`, which matters when each probe is expensive.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::bisect_range_by;
+///
+/// // Find the first release (out of 20) where a bug is present.
+/// let bugged_from = 7;
+/// assert_eq!(bisect_range_by(0, 20, |release| release >= bugged_from), 7);
+/// ```
+pub fn bisect_range_by<F>(lo: usize, hi: usize, mut pred: F) -> usize
+where
+    F: FnMut(usize) -> bool,
+{
+    let mut lo = lo;
+    let mut hi = hi;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+/// Search a sorted slice for the leftmost index whose element is not less
+/// than `x`, see [`crate::bisect_left`].
+///
+/// Rather than a dedicated descent, this reduces the slice lookup to the
+/// predicate form of [`bisect_range_by`], over the slice's indices.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use bisect_rs::bisect_range;
+/// let u = [0, 1, 2, 2, 3, 4];
+///
+/// assert_eq!(bisect_range(&u, &3), 4);
+/// assert_eq!(bisect_range(&u, &2), 2);
+/// ```
+pub fn bisect_range<T>(a: &[T], x: &T) -> usize
+where
+    T: Ord,
+{
+    bisect_range_by(0, a.len(), |i| &a[i] >= x)
+}
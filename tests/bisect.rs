@@ -1,5 +1,8 @@
 // Test _by variations? Implicitly called via bisect_left/right.
-use bisect_rs::{bisect_left, bisect_left_by_key, bisect_right, bisect_right_by_key};
+use bisect_rs::{
+    bisect_left, bisect_left_by_key, bisect_right, bisect_right_by_key, equal_range,
+    equal_range_by_key, try_bisect_right,
+};
 
 #[test]
 fn test_bisect_left() {
@@ -89,18 +92,29 @@ fn test_bisect_right() {
 }
 
 #[test]
-#[should_panic]
 fn test_bisect_right_overflow() {
-    // Not much I could do here. If a sequence filled with
-    // equal elements of size usize::MAX is passed, the
-    // function tries to return one past it.
-    //
-    // Looking at how Vec handles a similar case, a panic should
-    // be expected here.
+    // A sequence filled with usize::MAX equal elements used to make this
+    // panic, as the midpoint was computed as `(low + high) / 2`, which
+    // overflows once `low` and `high` both get large. Computing it as
+    // `low + (high - low) / 2` avoids the overflow, and the insertion
+    // point (`a.len()`) is always representable in a `usize` regardless.
     let b = [(); usize::MAX];
     assert_eq!(bisect_right(&b, &()), usize::MAX);
 }
 
+#[test]
+fn test_try_bisect_right() {
+    let sl: [i32; 0] = [];
+    assert_eq!(try_bisect_right(&sl, &0), Some(0));
+
+    let b = [1, 2, 4, 6, 8, 9];
+    assert_eq!(try_bisect_right(&b, &5), Some(3));
+    assert_eq!(try_bisect_right(&b, &9), Some(6));
+
+    let b = [(); usize::MAX];
+    assert_eq!(try_bisect_right(&b, &()), Some(usize::MAX));
+}
+
 #[test]
 fn test_bisect_left_by_key() {
     let s = [(0, 0), (2, 1), (4, 1), (5, 1), (3, 1),
@@ -132,4 +146,31 @@ fn test_bisect_right_by_key() {
     assert_eq!(bisect_right_by_key(&s, &"kdjdjfkdjd".len(), |e| e.len()), 6);
     assert_eq!(bisect_right_by_key(&s, &"gg".len(), |e| e.len()), 4);
     assert_eq!(bisect_right_by_key(&s, &"ccc".len(), |e| e.len()), 4);
+}
+
+#[test]
+fn test_equal_range() {
+    let sl: [i32; 0] = [];
+    assert_eq!(equal_range(&sl, &0), 0..0);
+
+    let b = [1, 3, 3, 3, 7];
+    assert_eq!(equal_range(&b, &0), 0..0);
+    assert_eq!(equal_range(&b, &1), 0..1);
+    assert_eq!(equal_range(&b, &3), 1..4);
+    assert_eq!(equal_range(&b, &7), 4..5);
+    assert_eq!(equal_range(&b, &8), 5..5);
+
+    let b = [1, 1, 1, 2, 3];
+    assert_eq!(equal_range(&b, &1), 0..3);
+}
+
+#[test]
+fn test_equal_range_by_key() {
+    let s = [(0, 0), (2, 1), (4, 1), (5, 1), (3, 1),
+            (1, 2), (2, 3), (4, 5), (5, 8), (3, 13),
+            (1, 21), (2, 34), (4, 55)];
+
+    assert_eq!(equal_range_by_key(&s, &1, |&(_, b)| b), 1..5);
+    assert_eq!(equal_range_by_key(&s, &13, |&(_, b)| b), 9..10);
+    assert_eq!(equal_range_by_key(&s, &-1, |&(_, b)| b), 0..0);
 }
\ No newline at end of file
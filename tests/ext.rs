@@ -0,0 +1,28 @@
+use bisect_rs::Bisect;
+
+#[test]
+fn test_lower_upper_bound() {
+    let b = [1, 3, 3, 3, 7];
+    assert_eq!(b.lower_bound(&3), 1);
+    assert_eq!(b.upper_bound(&3), 4);
+    assert_eq!(b.lower_bound(&0), 0);
+    assert_eq!(b.upper_bound(&8), 5);
+}
+
+#[test]
+fn test_lower_upper_bound_by_key() {
+    let s = [(0, 0), (2, 1), (4, 1), (5, 1), (3, 1)];
+    assert_eq!(s.lower_bound_by_key(&1, |&(_, b)| b), 1);
+    assert_eq!(s.upper_bound_by_key(&1, |&(_, b)| b), 5);
+}
+
+#[test]
+fn test_equal_range() {
+    let b = [1, 3, 3, 3, 7];
+    assert_eq!(b.equal_range(&3), 1..4);
+    assert_eq!(b.equal_range(&0), 0..0);
+    assert_eq!(b.equal_range(&7), 4..5);
+
+    let s = [(0, 0), (2, 1), (4, 1), (5, 1), (3, 1), (1, 2)];
+    assert_eq!(s.equal_range_by_key(&1, |&(_, b)| b), 1..5);
+}
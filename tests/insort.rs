@@ -0,0 +1,148 @@
+use bisect_rs::{
+    bisect_left_in, bisect_right_in, insort_left, insort_left_by_in, insort_left_by_key,
+    insort_left_by_key_in, insort_left_in, insort_right, insort_right_by_in, insort_right_by_key,
+    insort_right_by_key_in, insort_right_in,
+};
+
+#[test]
+fn test_bisect_left_in() {
+    let u = [0, 1, 2, 2, 2, 3, 4];
+    assert_eq!(bisect_left_in(&u, &2, ..), 2);
+    assert_eq!(bisect_left_in(&u, &2, 3..), 3);
+    assert_eq!(bisect_left_in(&u, &0, 1..), 1);
+    assert_eq!(bisect_left_in(&u, &4, ..5), 5);
+}
+
+#[test]
+fn test_bisect_right_in() {
+    let u = [0, 1, 2, 2, 2, 3, 4];
+    assert_eq!(bisect_right_in(&u, &2, ..), 5);
+    assert_eq!(bisect_right_in(&u, &2, ..4), 4);
+    assert_eq!(bisect_right_in(&u, &2, ..2), 2);
+}
+
+#[test]
+fn test_insort_left() {
+    let mut v = vec![1, 2, 2, 3];
+    insort_left(&mut v, 2);
+    assert_eq!(v, [1, 2, 2, 2, 3]);
+
+    let mut v = vec![];
+    insort_left(&mut v, 1);
+    assert_eq!(v, [1]);
+}
+
+#[test]
+fn test_insort_right() {
+    let mut v = vec![1, 2, 2, 3];
+    insort_right(&mut v, 2);
+    assert_eq!(v, [1, 2, 2, 2, 3]);
+
+    let mut v = vec![1, 2, 4];
+    insort_right(&mut v, 3);
+    assert_eq!(v, [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_insort_left_by_key() {
+    let mut v = vec![(1, "a"), (2, "b"), (2, "c"), (3, "d")];
+    insort_left_by_key(&mut v, (2, "z"), |&(k, _)| k);
+    assert_eq!(v, [(1, "a"), (2, "z"), (2, "b"), (2, "c"), (3, "d")]);
+}
+
+#[test]
+fn test_insort_right_by_key() {
+    let mut v = vec![(1, "a"), (2, "b"), (2, "c"), (3, "d")];
+    insort_right_by_key(&mut v, (2, "z"), |&(k, _)| k);
+    assert_eq!(v, [(1, "a"), (2, "b"), (2, "c"), (2, "z"), (3, "d")]);
+}
+
+#[test]
+fn test_insort_left_in() {
+    // Only v[1..5] is sorted; the unsorted ends must be left alone and the
+    // search restricted to the given sub-range.
+    let mut v = vec![9, 1, 2, 2, 3, 0];
+    insort_left_in(&mut v, 2, 1..5);
+    assert_eq!(v, [9, 1, 2, 2, 2, 3, 0]);
+}
+
+#[test]
+fn test_insort_right_in() {
+    let mut v = vec![9, 1, 2, 2, 3, 0];
+    insort_right_in(&mut v, 2, 1..5);
+    assert_eq!(v, [9, 1, 2, 2, 2, 3, 0]);
+}
+
+#[test]
+fn test_insort_left_by_in() {
+    // Sub-range search with a custom comparator; left of the two existing
+    // `2`s in the sorted window, regardless of the unsorted tags outside it.
+    let mut v = vec![(9, "z"), (1, "a"), (2, "b"), (2, "c"), (3, "d"), (0, "y")];
+    insort_left_by_in(&mut v, (2, "x"), 1..5, |&(k, _)| k.cmp(&2));
+    assert_eq!(
+        v,
+        [
+            (9, "z"),
+            (1, "a"),
+            (2, "x"),
+            (2, "b"),
+            (2, "c"),
+            (3, "d"),
+            (0, "y"),
+        ]
+    );
+}
+
+#[test]
+fn test_insort_right_by_in() {
+    let mut v = vec![(9, "z"), (1, "a"), (2, "b"), (2, "c"), (3, "d"), (0, "y")];
+    insort_right_by_in(&mut v, (2, "x"), 1..5, |&(k, _)| k.cmp(&2));
+    assert_eq!(
+        v,
+        [
+            (9, "z"),
+            (1, "a"),
+            (2, "b"),
+            (2, "c"),
+            (2, "x"),
+            (3, "d"),
+            (0, "y"),
+        ]
+    );
+}
+
+#[test]
+fn test_insort_left_by_key_in() {
+    let mut v = vec![(9, "z"), (1, "a"), (2, "b"), (2, "c"), (3, "d"), (0, "y")];
+    insort_left_by_key_in(&mut v, (2, "x"), 1..5, |&(k, _)| k);
+    assert_eq!(
+        v,
+        [
+            (9, "z"),
+            (1, "a"),
+            (2, "x"),
+            (2, "b"),
+            (2, "c"),
+            (3, "d"),
+            (0, "y"),
+        ]
+    );
+}
+
+#[test]
+fn test_insort_right_by_key_in() {
+    let mut v = vec![(9, "z"), (1, "a"), (2, "b"), (2, "c"), (3, "d"), (0, "y")];
+    insort_right_by_key_in(&mut v, (2, "x"), 1..5, |&(k, _)| k);
+    assert_eq!(
+        v,
+        [
+            (9, "z"),
+            (1, "a"),
+            (2, "b"),
+            (2, "c"),
+            (2, "x"),
+            (3, "d"),
+            (0, "y"),
+        ]
+    );
+}
@@ -0,0 +1,35 @@
+use bisect_rs::{bisect_range, bisect_range_by};
+
+#[test]
+fn test_bisect_range_by() {
+    assert_eq!(bisect_range_by(0, 20, |i| i >= 7), 7);
+    assert_eq!(bisect_range_by(0, 20, |_| false), 20);
+    assert_eq!(bisect_range_by(0, 20, |_| true), 0);
+    assert_eq!(bisect_range_by(5, 20, |i| i >= 7), 7);
+    assert_eq!(bisect_range_by(5, 5, |i| i >= 7), 5);
+}
+
+#[test]
+fn test_bisect_range_by_probe_bounds() {
+    // pred must never be called outside [lo, hi), and at most
+    // O(log(hi - lo)) times, even though probing is "expensive".
+    let lo = 3;
+    let hi = 1000;
+    let mut calls = 0;
+    let idx = bisect_range_by(lo, hi, |i| {
+        calls += 1;
+        assert!((lo..hi).contains(&i));
+        i >= 512
+    });
+    assert_eq!(idx, 512);
+    assert!(calls <= (hi - lo).next_power_of_two().trailing_zeros() as usize + 1);
+}
+
+#[test]
+fn test_bisect_range() {
+    let u = [0, 1, 2, 2, 3, 4];
+    assert_eq!(bisect_range(&u, &2), 2);
+    assert_eq!(bisect_range(&u, &3), 4);
+    assert_eq!(bisect_range(&u, &5), 6);
+    assert_eq!(bisect_range(&u, &0), 0);
+}